@@ -1,5 +1,5 @@
 import fc from "fast-check";
-import { CRLF, LF } from "../internal/constants.js";
+import { BOM, CRLF, LF } from "../internal/constants.js";
 
 export async function transform<I, O, T extends TransformStream<I, O>>(
   transformer: T,
@@ -88,7 +88,9 @@ export namespace FC {
           case "string16bits":
             return fc.string16bits(constraints);
         }
-      });
+      })
+      // NOTE: A BOM at the start of the input is stripped by the lexer.
+      .filter((v) => !v.startsWith(BOM));
   }
 
   export interface FieldConstraints extends TextConstraints {
@@ -0,0 +1,35 @@
+import { describe, expectTypeOf, it } from "vitest";
+import { CSVRecord, ParseOptions } from "../common/types.js";
+import { parseString } from "../parseString.js";
+import { RecordAssemblerTransformar } from "../transformers/RecordAssemblerTransformar.js";
+
+describe("nullValues option", () => {
+  it("should not be accepted by the parse functions", () => {
+    expectTypeOf<ParseOptions<["a"]>>().not.toHaveProperty("nullValues");
+    expectTypeOf(parseString<["a"]>).returns.toEqualTypeOf<
+      AsyncIterableIterator<CSVRecord<["a"]>>
+    >();
+  });
+
+  it("should make RecordAssemblerTransformar emit null values", () => {
+    const transformer = new RecordAssemblerTransformar({
+      header: ["a"] as const,
+      nullValues: ["NA"],
+    });
+    expectTypeOf(transformer.readable).toEqualTypeOf<
+      ReadableStream<Record<"a", string | null | undefined>>
+    >();
+  });
+
+  it("should not add null to the records unless it is specified", () => {
+    expectTypeOf<RecordAssemblerTransformar<["a"]>["readable"]>().toEqualTypeOf<
+      ReadableStream<Record<"a", string | undefined>>
+    >();
+    const transformer = new RecordAssemblerTransformar({
+      header: ["a"] as const,
+    });
+    expectTypeOf(transformer.readable).toEqualTypeOf<
+      ReadableStream<Record<"a", string | undefined>>
+    >();
+  });
+});
@@ -41,4 +41,21 @@ describe("parseString function", () => {
         },
       ),
     ));
+
+  describe("edge cases", () => {
+    for (const [title, csv, expected] of [
+      ["empty input", "", []],
+      ["empty lines only", "\r\n\n", []],
+      ["whitespace only", "  ", []],
+      ["header only", "a,b", []],
+      ["header only with trailing newline", "a,b\r\n", []],
+      ["empty lines before the header", "\n\na\n1\n", [{ a: "1" }]],
+      ["last record without trailing newline", "a,b\n1", [{ a: "1" }]],
+      ["single column without trailing newline", "a\n1", [{ a: "1" }]],
+    ] as const) {
+      it(`should parse ${title}`, async () => {
+        expect(await parseString.toArray(csv)).toEqual(expected);
+      });
+    }
+  });
 });
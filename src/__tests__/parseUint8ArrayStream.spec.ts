@@ -68,6 +68,15 @@ describe("parseUint8ArrayStream function", () => {
     }
   });
 
+  it("should keep the BOM if ignoreBOM is true", async () => {
+    const csv = new SingleValueReadableStream("\uFEFFa,b,c\n1,2,3").pipeThrough(
+      new TextEncoderStream(),
+    );
+    expect(
+      await parseUint8ArrayStream.toArray(csv, { ignoreBOM: true }),
+    ).toStrictEqual([{ "\uFEFFa": "1", b: "2", c: "3" }]);
+  });
+
   it("should parse CSV with BOM and EOL", async () => {
     const csv = new SingleValueReadableStream(
       "\uFEFFa,b,c\r\n1,2,3\r\n",
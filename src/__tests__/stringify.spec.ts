@@ -0,0 +1,150 @@
+import { fc } from "@fast-check/vitest";
+import { describe, expect, it } from "vitest";
+import { parseString } from "../parseString.js";
+import { stringify } from "../stringify.js";
+import { FC } from "./helper.js";
+
+describe("stringify function", () => {
+  it("should serialize records to RFC 4180 CSV", () => {
+    expect(
+      stringify([
+        { name: "Alice", comment: 'say "hi"' },
+        { name: "Bob", comment: "a,b\nc" },
+        { name: "Charlie", comment: null },
+      ]),
+    ).toBe(
+      'name,comment\r\nAlice,"say ""hi"""\r\nBob,"a,b\nc"\r\nCharlie,\r\n',
+    );
+  });
+
+  it("should serialize array records", () => {
+    expect(stringify([["a", "b"], [""]], { header: ["x", "y"] })).toBe(
+      'x,y\r\na,b\r\n""\r\n',
+    );
+  });
+
+  it("should quote a BOM only at the start of the output", async () => {
+    const csv = stringify([["\uFEFFa", "b"]], { header: ["\uFEFFx", "y"] });
+    expect(csv).toBe('"\uFEFFx",y\r\n\uFEFFa,b\r\n');
+    expect(
+      stringify([["\uFEFFa"]], { header: ["x"], quoteStyle: "never" }),
+    ).toBe("x\r\n\uFEFFa\r\n");
+    expect(await parseString.toArray(csv)).toStrictEqual([
+      { "\uFEFFx": "\uFEFFa", y: "b" },
+    ]);
+  });
+
+  it("should be parsed back to the same records", () =>
+    fc.assert(
+      fc.asyncProperty(
+        fc.gen().map((g) => {
+          const header = g(FC.header);
+          const demiliter = g(FC.demiliter, { excludes: ['"'] });
+          const records = g(FC.csvData, {
+            columnsConstraints: {
+              minLength: header.length,
+              maxLength: header.length,
+            },
+            fieldConstraints: { minLength: 1 },
+          }).map((row) =>
+            Object.fromEntries(row.map((v, i) => [header[i], v])),
+          );
+          return { header, demiliter, records };
+        }),
+        async ({ header, demiliter, records }) => {
+          const csv = stringify(records, { header, demiliter });
+          expect(await parseString.toArray(csv, { demiliter })).toEqual(
+            records,
+          );
+        },
+      ),
+    ));
+
+  describe("quoteStyle option", () => {
+    const records = [{ a: "1.5", b: "x" }];
+
+    it("should quote fields as specified", () => {
+      expect(stringify(records, { quoteStyle: "all" })).toBe(
+        '"a","b"\r\n"1.5","x"\r\n',
+      );
+      expect(stringify(records, { quoteStyle: "nonNumeric" })).toBe(
+        '"a","b"\r\n1.5,"x"\r\n',
+      );
+      expect(stringify(records, { quoteStyle: "never" })).toBe(
+        "a,b\r\n1.5,x\r\n",
+      );
+    });
+
+    it("should throw error if a field needs to be quoted with never", () => {
+      expect(() =>
+        stringify([{ a: "x,y" }], { quoteStyle: "never" }),
+      ).toThrowError(
+        'The field "x,y" must be quoted, but quoteStyle is "never".',
+      );
+      expect(() =>
+        stringify([{ "\uFEFFa": "x" }], { quoteStyle: "never" }),
+      ).toThrowError(
+        'The field "\uFEFFa" must be quoted, but quoteStyle is "never".',
+      );
+      expect(() =>
+        stringify([[""]], { header: ["a"], quoteStyle: "never" }),
+      ).toThrowError(
+        'The empty field of a single-field record must be quoted, but quoteStyle is "never".',
+      );
+    });
+  });
+
+  describe("nullValue option", () => {
+    it("should write null and undefined fields as the value", () => {
+      expect(
+        stringify([{ a: null, b: "x" }, ["", undefined]], { nullValue: "NA" }),
+      ).toBe("a,b\r\nNA,x\r\n,NA\r\n");
+      expect(stringify([[null]], { header: ["a"], nullValue: "" })).toBe(
+        'a\r\n""\r\n',
+      );
+    });
+  });
+
+  describe("booleanValues option", () => {
+    it("should write booleans as the values", () => {
+      const records = [{ a: true, b: false }];
+      expect(stringify(records)).toBe("a,b\r\ntrue,false\r\n");
+      expect(stringify(records, { booleanValues: ["1", "0"] })).toBe(
+        "a,b\r\n1,0\r\n",
+      );
+    });
+  });
+
+  describe("escape option", () => {
+    it("should escape quotations with the escape character", () => {
+      expect(stringify([['a"b', "c\\d"]], { escape: "\\" })).toBe(
+        '"a\\"b","c\\\\d"\r\n',
+      );
+    });
+
+    it("should be parsed back to the same records", () =>
+      fc.assert(
+        fc.asyncProperty(
+          fc.gen().map((g) => {
+            const header = g(FC.header);
+            const records = g(FC.csvData, {
+              columnsConstraints: {
+                minLength: header.length,
+                maxLength: header.length,
+              },
+              fieldConstraints: { minLength: 1 },
+            }).map((row) =>
+              Object.fromEntries(row.map((v, i) => [header[i], v])),
+            );
+            return { header, records };
+          }),
+          async ({ header, records }) => {
+            const csv = stringify(records, { header, escape: "\\" });
+            expect(await parseString.toArray(csv, { escape: "\\" })).toEqual(
+              records,
+            );
+          },
+        ),
+      ));
+  });
+});
@@ -0,0 +1,8 @@
+import { describe, expectTypeOf, it } from "vitest";
+import { ParseOptions } from "../common/types.js";
+
+describe("unitsRow option", () => {
+  it("should not be accepted by the parse functions", () => {
+    expectTypeOf<ParseOptions<["a"]>>().not.toHaveProperty("unitsRow");
+  });
+});
@@ -0,0 +1,41 @@
+import { fc } from "@fast-check/vitest";
+import { describe, expect, it } from "vitest";
+import { escapeField } from "../internal/escapeField.js";
+import { verifyRoundTrip } from "../verifyRoundTrip.js";
+import { FC } from "./helper.js";
+
+describe("verifyRoundTrip function", () => {
+  it("should report no differences for CSV that is read back the same", () =>
+    fc.assert(
+      fc.asyncProperty(
+        fc.gen().map((g) => {
+          const header = g(FC.header);
+          const EOL = g(FC.eol);
+          const csvData = g(FC.csvData, {
+            columnsConstraints: {
+              minLength: header.length,
+              maxLength: header.length,
+            },
+          });
+          return [header, ...csvData]
+            .map((row) => row.map((value) => escapeField(value)).join(","))
+            .join(EOL);
+        }),
+        async (csv) => {
+          expect(await verifyRoundTrip(csv)).toStrictEqual([]);
+        },
+      ),
+    ));
+
+  it("should report quoted empty fields that are read back as missing", async () => {
+    expect(await verifyRoundTrip('name,age\nAlice,42\nBob,""')).toStrictEqual([
+      { index: 1, column: "age", expected: "", actual: undefined },
+    ]);
+  });
+
+  it("should use the dialect to serialize and parse again", async () => {
+    expect(
+      await verifyRoundTrip('a;b\n"x\\"y";z', { demiliter: ";", escape: "\\" }),
+    ).toStrictEqual([]);
+  });
+});
@@ -50,6 +50,49 @@ export interface CommonOptions {
    * @default '"'
    */
   quotation?: string;
+  /**
+   * Escape character for quotations in quoted fields.
+   *
+   * @remarks
+   * By default, a quotation in a quoted field is escaped by doubling it
+   * as defined in RFC 4180 (`"a""b"`).
+   *
+   * Some exports, such as those of PostgreSQL or MySQL,
+   * escape it with a backslash instead (`"a\"b"`).
+   * If you specify `'\\'`, the quotation and the escape character itself
+   * are unescaped when they follow the escape character in a quoted field.
+   * Doubled quotations are still accepted.
+   *
+   * @default undefined
+   */
+  escape?: string;
+  /**
+   * Prefix of comment lines.
+   *
+   * @remarks
+   * If you specify this option (e.g. `'#'`),
+   * lines starting with it are skipped.
+   * It is only recognized at the start of a record,
+   * so it has no effect inside fields or quoted fields.
+   * It must not overlap the demiliter or the quotation.
+   *
+   * @default undefined
+   */
+  comment?: string;
+  /**
+   * Treat quotations in the middle of unquoted fields as literal characters.
+   *
+   * @remarks
+   * Some exports contain stray quotations, such as `he said "hi", there`.
+   * By default, a quotation always starts a quoted field,
+   * so such a field is read as `he said hi`,
+   * or an error is thrown if the quotation is never closed.
+   *
+   * If you specify true, quotations that do not start a field are kept as is.
+   *
+   * @default false
+   */
+  relaxQuotes?: boolean;
 }
 
 /**
@@ -80,11 +123,12 @@ export interface BinaryOptions {
    */
   charset?: string;
   /**
-   * If the binary has a BOM, you can specify whether to ignore it.
+   * If the binary has a BOM, you can specify whether to keep it.
    *
    * @remarks
-   * If you specify true, the BOM will be ignored.
-   * If you specify false or not specify it, the BOM will be treated as a normal character.
+   * If you specify true, the BOM will be treated as a normal character,
+   * so it becomes part of the first field.
+   * If you specify false or not specify it, the BOM will be removed.
    * See {@link https://developer.mozilla.org/en-US/docs/Web/API/TextDecoderStream/ignoreBOM | TextDecoderOptions.ignoreBOM} for more information about the BOM.
    * @default false
    */
@@ -131,15 +175,274 @@ export interface RecordAssemblerOptions<Header extends ReadonlyArray<string>> {
    * @default undefined
    */
   header?: Header;
+  /**
+   * Whether the first record is a header.
+   *
+   * @remarks
+   * If you specify false,
+   * the first record is treated as a normal record,
+   * and the header names are generated as `column_0`, `column_1`, ...
+   * from the number of fields in the first record.
+   *
+   * To use your own names instead,
+   * specify {@link RecordAssemblerOptions.header}.
+   *
+   * This option is ignored if {@link RecordAssemblerOptions.header} is specified.
+   *
+   * @default true
+   */
+  hasHeader?: boolean;
+  /**
+   * Number of records to skip at the beginning, before the header.
+   *
+   * @remarks
+   * Use this option to ignore the preamble lines of vendor exports.
+   * Empty lines are counted as records.
+   *
+   * @default 0
+   */
+  skipRows?: number;
+  /**
+   * Number of records to skip at the end.
+   *
+   * @remarks
+   * Use this option to ignore the summary lines of vendor exports.
+   * Empty lines are not counted.
+   *
+   * The last records are held back until the end of the input,
+   * so each record is emitted `skipFooterRows` records late.
+   *
+   * @default 0
+   */
+  skipFooterRows?: number;
+  /**
+   * Number of records that make up the header.
+   *
+   * @remarks
+   * Some exports have a multi-row header, such as a group row followed by a field row.
+   * If you specify `2` or more,
+   * the header names are made by joining the non-empty values of each column
+   * with {@link RecordAssemblerOptions.headerJoin}.
+   *
+   * An empty value in a row other than the last one
+   * takes over the value on its left, as a merged cell does.
+   *
+   * This option is ignored if {@link RecordAssemblerOptions.header} is specified.
+   *
+   * @default 1
+   */
+  headerRows?: number;
+  /**
+   * Separator used to join the header rows.
+   *
+   * @remarks
+   * See {@link RecordAssemblerOptions.headerRows}.
+   *
+   * @default '.'
+   */
+  headerJoin?: string;
+  /**
+   * Treat the record following the header as a units row.
+   *
+   * @remarks
+   * Some exports, such as those of scientific instruments,
+   * have a row of per-column units right after the header.
+   *
+   * If you specify true,
+   * the units row is not emitted as a record,
+   * and can be inspected with {@link RecordAssemblerTransformar.units}.
+   * This option is only for {@link RecordAssemblerTransformar}.
+   * Empty lines before it are skipped,
+   * and options that transform records, such as
+   * {@link RecordAssemblerOptions.nullValues}, are not applied to it.
+   *
+   * @default false
+   */
+  unitsRow?: boolean;
+  /**
+   * Fix empty and duplicated header names instead of throwing an error.
+   *
+   * @remarks
+   * If you specify true,
+   * empty header names are replaced with `column_N`
+   * (where `N` is the index of the column),
+   * and duplicated header names are suffixed with `_1`, `_2`, ...
+   * so that every header name is unique and not empty.
+   *
+   * The renamed headers can be inspected with
+   * {@link RecordAssemblerTransformar.renamedHeaders}.
+   * The report is only available from the low-level transformer;
+   * the parse functions rename the headers silently.
+   *
+   * If you specify false or not specify it,
+   * a header that contains duplicated fields throws an error.
+   *
+   * @default false
+   */
+  fixHeader?: boolean;
+  /**
+   * How to handle records that have more fields than the header.
+   *
+   * @remarks
+   * If you specify `'drop'`, the extra fields are ignored.
+   *
+   * If you specify `'error'`, an error is thrown.
+   *
+   * This option is ignored if {@link RecordAssemblerOptions.restColumn} is specified.
+   *
+   * @default 'drop'
+   */
+  extraData?: ExtraDataPolicy;
+  /**
+   * Name of the column that collects the fields beyond the header.
+   *
+   * @remarks
+   * If you specify this option,
+   * the fields beyond the header are joined with the field delimiter
+   * and stored in this column instead of being dropped.
+   * Each field is escaped with {@link CommonOptions.quotation}
+   * and {@link CommonOptions.escape} as needed,
+   * so the value can be parsed again as a CSV record with the same options.
+   * If the record has no extra fields, the value is `undefined`.
+   *
+   * The name must not be one of the header names.
+   *
+   * @default undefined
+   */
+  restColumn?: string;
+  /**
+   * How strictly the number of fields is checked against the header.
+   *
+   * @remarks
+   * If you specify `'strict'`,
+   * an error is thrown for the first record whose number of fields
+   * differs from the header, including records without values such as `,,`.
+   * The error reports the record number,
+   * which counts every record of the input from 1,
+   * including the header, records skipped by
+   * {@link RecordAssemblerOptions.skipRows} and empty lines,
+   * but not comment lines.
+   * Records with more fields are still allowed
+   * if {@link RecordAssemblerOptions.restColumn} is specified.
+   *
+   * If you specify `'loose'`,
+   * missing fields are `undefined`,
+   * and extra fields are handled by {@link RecordAssemblerOptions.extraData}.
+   *
+   * @default 'loose'
+   */
+  columnCount?: ColumnCountPolicy;
+  /**
+   * Columns to include in the records.
+   *
+   * @remarks
+   * If you specify this option,
+   * only the specified columns are included in the records.
+   * Columns can be specified by header name or by index.
+   *
+   * An error is thrown if a header name is not found in the header,
+   * or if an index is not an integer within the range of the header.
+   *
+   * @default undefined
+   */
+  columns?: ReadonlyArray<string | number>;
+  /**
+   * Which side of the fields to trim whitespace from.
+   *
+   * @remarks
+   * Use this option to clean whitespace-padded exports.
+   * The header and quoted fields are also trimmed.
+   *
+   * @default 'none'
+   */
+  trim?: TrimPolicy;
+  /**
+   * Field values to be treated as `null`.
+   *
+   * @remarks
+   * If you specify this option (e.g. `['', 'NA', 'NULL']`),
+   * fields that match one of the values are emitted as `null`.
+   * Values are compared after {@link RecordAssemblerOptions.trim} is applied.
+   *
+   * Missing fields are still `undefined`.
+   *
+   * This option is only available in {@link RecordAssemblerTransformar},
+   * since the parse functions emit records of strings ({@link CSVRecord}).
+   *
+   * @default undefined
+   */
+  nullValues?: ReadonlyArray<string>;
+}
+
+/**
+ * Which side of the fields to trim whitespace from.
+ * @category Types
+ */
+export type TrimPolicy = "none" | "left" | "right" | "both";
+
+/**
+ * Policy for records that have more fields than the header.
+ * @category Types
+ */
+export type ExtraDataPolicy = "drop" | "error";
+
+/**
+ * How strictly the number of fields in each record is checked.
+ * @category Types
+ */
+export type ColumnCountPolicy = "loose" | "strict";
+
+/**
+ * A record assembled by {@link RecordAssemblerTransformar}.
+ * @category Types
+ * @template Header Header of the CSV.
+ * @template NullValues Type of {@link RecordAssemblerOptions.nullValues}.
+ *
+ * @remarks
+ * Values can be `null` only if {@link RecordAssemblerOptions.nullValues} is specified.
+ */
+export type AssembledRecord<
+  Header extends ReadonlyArray<string>,
+  NullValues extends ReadonlyArray<string> | undefined = undefined,
+> = Record<
+  Header[number],
+  | string
+  | undefined
+  | (NullValues extends ReadonlyArray<string> ? null : never)
+>;
+
+/**
+ * A header name renamed by the {@link RecordAssemblerOptions.fixHeader} option.
+ * @category Types
+ */
+export interface HeaderRename {
+  /**
+   * Index of the column.
+   */
+  index: number;
+  /**
+   * Original header name.
+   */
+  from: string;
+  /**
+   * Renamed header name.
+   */
+  to: string;
 }
 
 /**
  * Parse options for CSV string.
  * @category Types
+ *
+ * @remarks
+ * {@link RecordAssemblerOptions.nullValues} is not included,
+ * since the parse functions emit records of strings.
+ * {@link RecordAssemblerOptions.unitsRow} is not included either,
+ * since the parse functions have no way to return the units.
  */
 export interface ParseOptions<Header extends ReadonlyArray<string>>
   extends CommonOptions,
-    RecordAssemblerOptions<Header> {}
+    Omit<RecordAssemblerOptions<Header>, "nullValues" | "unitsRow"> {}
 
 /**
  * Parse options for CSV binary.
@@ -149,6 +452,103 @@ export interface ParseBinaryOptions<Header extends ReadonlyArray<string>>
   extends ParseOptions<Header>,
     BinaryOptions {}
 
+/**
+ * Options for serializing records to CSV.
+ * @category Types
+ */
+export interface StringifyOptions<Header extends ReadonlyArray<string>>
+  extends Pick<CommonOptions, "demiliter" | "quotation" | "escape"> {
+  /**
+   * CSV header.
+   *
+   * @remarks
+   * The header is written as the first record,
+   * and the fields of object records are written in its order.
+   *
+   * If you don't specify this option,
+   * the keys of the first record are used if it is an object,
+   * and no header is written if it is an array.
+   *
+   * @default undefined
+   */
+  header?: Header;
+  /**
+   * Which fields to quote.
+   *
+   * @remarks
+   * - `'minimal'`: Only fields that contain the demiliter, the quotation, CR, or LF.
+   * - `'all'`: All fields.
+   * - `'nonNumeric'`: All fields that are not numbers.
+   * - `'never'`: No fields. An error is thrown if a field needs to be quoted.
+   *
+   * @default 'minimal'
+   */
+  quoteStyle?: QuoteStyle;
+  /**
+   * Value written for `null` and `undefined` fields.
+   *
+   * @remarks
+   * Some systems expect a sentinel such as `'NA'` or `'NULL'`
+   * instead of an empty field.
+   * The value is quoted if it needs to be, as other fields are.
+   *
+   * @default ''
+   */
+  nullValue?: string;
+  /**
+   * Values written for `true` and `false` fields, in this order.
+   *
+   * @remarks
+   * Specify `['1', '0']` for systems that expect numbers.
+   *
+   * @default ['true', 'false']
+   */
+  booleanValues?: readonly [string, string];
+}
+
+/**
+ * Which fields to quote when serializing records.
+ * @category Types
+ */
+export type QuoteStyle = "minimal" | "all" | "nonNumeric" | "never";
+
+/**
+ * A record to be serialized to CSV.
+ *
+ * @remarks
+ * `null` and `undefined` values are written as empty fields,
+ * or as {@link StringifyOptions.nullValue} if it is specified.
+ * Booleans are written as {@link StringifyOptions.booleanValues}.
+ *
+ * @category Types
+ */
+export type SerializableRecord<Header extends ReadonlyArray<string>> =
+  | Partial<Record<Header[number], string | boolean | null>>
+  | ReadonlyArray<string | boolean | null | undefined>;
+
+/**
+ * A value-level difference found by {@link verifyRoundTrip}.
+ * @category Types
+ */
+export interface RoundTripDifference {
+  /**
+   * Index of the record, counted from 0.
+   */
+  index: number;
+  /**
+   * Name of the column.
+   */
+  column: string;
+  /**
+   * Value parsed from the input.
+   */
+  expected: string | undefined;
+  /**
+   * Value parsed again after the records are serialized.
+   */
+  actual: string | undefined;
+}
+
 /**
  * CSV Record.
  * @category Types
@@ -8,3 +8,5 @@ export * from "./parseStringStream.js";
 export * from "./parseResponse.js";
 export * from "./parseStream.js";
 export * from "./parse.js";
+export * from "./stringify.js";
+export * from "./verifyRoundTrip.js";
@@ -0,0 +1,113 @@
+import {
+  QuoteStyle,
+  SerializableRecord,
+  StringifyOptions,
+} from "../common/types.js";
+import { assertCommonOptions } from "./assertCommonOptions.js";
+import { BOM, COMMA, CRLF, DOUBLE_QUATE } from "./constants.js";
+import { serializeRecord } from "./serializeRecord.js";
+
+/**
+ * Serializer of records to CSV lines, shared by the stringify APIs.
+ */
+export class RecordSerializer<Header extends ReadonlyArray<string>> {
+  #demiliter: string;
+  #quotation: string;
+  #escape: string | undefined;
+  #quoteStyle: QuoteStyle;
+  #header: ReadonlyArray<string> | undefined;
+  #nullValue: string | undefined;
+  #booleanValues: readonly [string, string];
+  #started = false;
+  #firstLine = true;
+
+  constructor(options: StringifyOptions<Header> = {}) {
+    const {
+      demiliter = COMMA,
+      quotation = DOUBLE_QUATE,
+      escape,
+      quoteStyle = "minimal",
+      nullValue,
+      booleanValues = ["true", "false"],
+    } = options;
+    assertCommonOptions({ demiliter, quotation, escape });
+    this.#demiliter = demiliter;
+    this.#quotation = quotation;
+    this.#escape = escape;
+    this.#quoteStyle = quoteStyle;
+    this.#header = options.header;
+    this.#nullValue = nullValue;
+    this.#booleanValues = booleanValues;
+  }
+
+  /**
+   * Serialize a record.
+   *
+   * @returns The CSV lines of the record,
+   * preceded by the header line if the record is the first one.
+   */
+  public serialize(record: SerializableRecord<Header>): string {
+    const csv = this.#start(record);
+    if (Array.isArray(record)) {
+      return csv + this.#line(record);
+    }
+    if (this.#header === undefined) {
+      throw new Error(
+        "The header must be specified to serialize object records after array records.",
+      );
+    }
+    const fields = this.#header.map(
+      (name) =>
+        (record as Record<string, string | boolean | null | undefined>)[name],
+    );
+    return csv + this.#line(fields);
+  }
+
+  /**
+   * Finish serializing.
+   *
+   * @returns The header line if no record has been serialized, otherwise empty.
+   */
+  public flush(): string {
+    return this.#start();
+  }
+
+  #start(record?: SerializableRecord<Header>): string {
+    if (this.#started) {
+      return "";
+    }
+    this.#started = true;
+    if (
+      this.#header === undefined &&
+      record !== undefined &&
+      !Array.isArray(record)
+    ) {
+      this.#header = Object.keys(record);
+    }
+    return this.#header === undefined ? "" : this.#line(this.#header);
+  }
+
+  #line(fields: ReadonlyArray<string | boolean | null | undefined>): string {
+    const values = fields.map((field) => this.#format(field));
+    // NOTE: A BOM at the start of the output is quoted,
+    // otherwise it would be stripped when it is read back.
+    const quoteFirstField = this.#firstLine && !!values[0]?.startsWith(BOM);
+    this.#firstLine = false;
+    return (
+      serializeRecord(values, {
+        demiliter: this.#demiliter,
+        quotation: this.#quotation,
+        escape: this.#escape,
+        quoteStyle: this.#quoteStyle,
+        quoteFirstField,
+      }) + CRLF
+    );
+  }
+
+  #format(value: string | boolean | null | undefined): string | undefined {
+    if (typeof value === "boolean") {
+      return this.#booleanValues[value ? 0 : 1];
+    }
+    return value ?? this.#nullValue;
+  }
+}
@@ -1,12 +1,15 @@
 import { CommonOptions } from "../common/types.js";
-import { CR, LF } from "./constants.js";
+import { BOM, CR, LF } from "./constants.js";
 
 /**
  * Assert that the options are valid.
  *
  * @param options The options to assert.
  */
-export function assertCommonOptions(options: Required<CommonOptions>): void {
+export function assertCommonOptions(
+  options: Required<Pick<CommonOptions, "demiliter" | "quotation">> &
+    CommonOptions,
+): void {
   if (typeof options.quotation === "string" && options.quotation.length === 0) {
     throw new Error("quotation must not be empty");
   }
@@ -19,6 +22,14 @@ export function assertCommonOptions(options: Required<CommonOptions>): void {
   if (options.demiliter.includes(LF) || options.demiliter.includes(CR)) {
     throw new Error("demiliter must not include CR or LF");
   }
+  // NOTE: A BOM at the start of the input is stripped,
+  // so it would eat a leading quotation or demiliter.
+  if (options.quotation.startsWith(BOM)) {
+    throw new Error("quotation must not start with BOM");
+  }
+  if (options.demiliter.startsWith(BOM)) {
+    throw new Error("demiliter must not start with BOM");
+  }
   if (
     options.demiliter.includes(options.quotation) ||
     options.quotation.includes(options.demiliter)
@@ -27,4 +38,44 @@ export function assertCommonOptions(options: Required<CommonOptions>): void {
       "demiliter and quotation must not include each other as a substring",
     );
   }
+  if (options.escape !== undefined) {
+    if (options.escape.length === 0) {
+      throw new Error("escape must not be empty");
+    }
+    if (options.escape.includes(LF) || options.escape.includes(CR)) {
+      throw new Error("escape must not include CR or LF");
+    }
+    if (
+      options.demiliter.includes(options.escape) ||
+      options.escape.includes(options.demiliter)
+    ) {
+      throw new Error(
+        "demiliter and escape must not include each other as a substring",
+      );
+    }
+  }
+  if (options.comment !== undefined) {
+    if (options.comment.length === 0) {
+      throw new Error("comment must not be empty");
+    }
+    if (options.comment.includes(LF) || options.comment.includes(CR)) {
+      throw new Error("comment must not include CR or LF");
+    }
+    if (
+      options.demiliter.includes(options.comment) ||
+      options.comment.includes(options.demiliter)
+    ) {
+      throw new Error(
+        "demiliter and comment must not include each other as a substring",
+      );
+    }
+    if (
+      options.quotation.includes(options.comment) ||
+      options.comment.includes(options.quotation)
+    ) {
+      throw new Error(
+        "quotation and comment must not include each other as a substring",
+      );
+    }
+  }
 }
@@ -2,6 +2,11 @@ export const CR = "\r";
 export const CRLF = "\r\n";
 export const LF = "\n";
 
+/**
+ * BOM is a symbol for the byte order mark (U+FEFF).
+ */
+export const BOM = "\uFEFF";
+
 /**
  * COMMA is a symbol for comma(,).
  */
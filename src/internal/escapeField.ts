@@ -37,6 +37,7 @@ export function escapeField(
   {
     quotation = DOUBLE_QUATE,
     demiliter = COMMA,
+    escape,
     quate,
   }: EscapeFieldOptions = {},
 ): string {
@@ -65,10 +66,16 @@ export function escapeField(
     CHECK_CACHE.set(demiliter, check);
   }
 
-  const contents = value.replaceAll(quotation, replacedPattern);
+  const contents =
+    escape === undefined || escape === quotation
+      ? value.replaceAll(quotation, replacedPattern)
+      : value
+          .replaceAll(escape, escape + escape)
+          .replaceAll(quotation, escape + quotation);
 
   if (
     quate ||
+    contents !== value ||
     contents.includes(quotation) ||
     contents.includes(demiliter) ||
     contents.includes("\n") ||
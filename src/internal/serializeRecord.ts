@@ -0,0 +1,62 @@
+import { CommonOptions, QuoteStyle } from "../common/types.js";
+import { escapeField } from "./escapeField.js";
+
+const NUMERIC_PATTERN = /^[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?$/;
+
+export interface SerializeRecordOptions
+  extends Required<Pick<CommonOptions, "demiliter" | "quotation">>,
+    Pick<CommonOptions, "escape"> {
+  quoteStyle: QuoteStyle;
+  /**
+   * Quote the first field regardless of the quote style.
+   */
+  quoteFirstField?: boolean;
+}
+
+/**
+ * Serialize the fields of a record to a CSV line without a record delimiter.
+ *
+ * DO NOT USE THIS FUNCTION BEFORE ASSTPTED BY `{@link assertCommonOptions}`.
+ * @param fields The fields of the record.
+ * @param options The options.
+ * @returns The serialized record.
+ */
+export function serializeRecord(
+  fields: ReadonlyArray<string | null | undefined>,
+  { quoteStyle, quoteFirstField = false, ...options }: SerializeRecordOptions,
+): string {
+  // NOTE: A record with a single empty field is quoted,
+  // otherwise it would be read back as an empty line.
+  if (fields.length === 1 && !fields[0]) {
+    if (quoteStyle === "never") {
+      throw new Error(
+        'The empty field of a single-field record must be quoted, but quoteStyle is "never".',
+      );
+    }
+    return options.quotation.repeat(2);
+  }
+  return fields
+    .map((field, index) => {
+      const value = field ?? "";
+      const quate = index === 0 && quoteFirstField ? true : undefined;
+      switch (quoteStyle) {
+        case "all":
+          return escapeField(value, { ...options, quate: true });
+        case "nonNumeric":
+          return escapeField(value, {
+            ...options,
+            quate: NUMERIC_PATTERN.test(value) ? undefined : true,
+          });
+        case "never":
+          if (quate || escapeField(value, options) !== value) {
+            throw new Error(
+              `The field ${JSON.stringify(value)} must be quoted, but quoteStyle is "never".`,
+            );
+          }
+          return value;
+        default:
+          return escapeField(value, { ...options, quate });
+      }
+    })
+    .join(options.demiliter);
+}
@@ -155,7 +155,6 @@ export function parse<Header extends ReadonlyArray<string>>(
  *
  * for await (const record of parse(response, {
  *   charset: 'shift-jis',
- *   ignoreBOM: true,
  *   decomposition: 'gzip',
  * })) {
  *   // ...
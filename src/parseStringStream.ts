@@ -49,7 +49,16 @@ export async function* parseStringStream<Header extends ReadonlyArray<string>>(
   });
   await stream
     .pipeThrough(new LexerTransformer(options))
-    .pipeThrough(new RecordAssemblerTransformar(options))
+    // NOTE: nullValues and unitsRow are dropped
+    // even if they are passed without type checking,
+    // so that records are made of strings and no row is consumed silently.
+    .pipeThrough(
+      new RecordAssemblerTransformar({
+        ...options,
+        nullValues: undefined,
+        unitsRow: undefined,
+      }),
+    )
     .pipeTo(
       new WritableStream({
         write: (row) => controller.enqueue(row),
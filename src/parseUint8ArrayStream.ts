@@ -53,6 +53,8 @@ export async function* parseUint8ArrayStream<
       (stream, transformer) => stream.pipeThrough(transformer),
       stream,
     ),
+    // NOTE: ignoreBOM is also passed to LexerTransformer through the options,
+    // so that the BOM is kept in the first field.
     options,
   );
 }
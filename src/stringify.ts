@@ -0,0 +1,45 @@
+import { SerializableRecord, StringifyOptions } from "./common/types.js";
+import { RecordSerializer } from "./internal/RecordSerializer.js";
+
+/**
+ * Serialize records to a CSV string.
+ *
+ * @category Middle-level API
+ * @param records Records to serialize. Each record can be an object or an array.
+ * @param options Serializing options. See {@link StringifyOptions}.
+ * @returns CSV string as defined in RFC 4180.
+ *
+ * @remarks
+ * By default, fields that contain the demiliter, the quotation, CR, or LF are quoted,
+ * and quotations in them are escaped by doubling them
+ * (or with the escape character if {@link CommonOptions.escape} is specified).
+ * See {@link StringifyOptions.quoteStyle} to change which fields are quoted.
+ * Each record, including the last one, is terminated by CRLF.
+ *
+ * @example Serializing records to a CSV string
+ *
+ * ```ts
+ * import { stringify } from 'web-csv-toolbox';
+ *
+ * const csv = stringify([
+ *   { name: 'Alice', age: '42' },
+ *   { name: 'Bob', age: '69' },
+ * ]);
+ * console.log(csv);
+ * // Prints:
+ * // name,age
+ * // Alice,42
+ * // Bob,69
+ * ```
+ */
+export function stringify<Header extends ReadonlyArray<string>>(
+  records: Iterable<SerializableRecord<Header>>,
+  options: StringifyOptions<Header> = {},
+): string {
+  const serializer = new RecordSerializer(options);
+  let csv = "";
+  for (const record of records) {
+    csv += serializer.serialize(record);
+  }
+  return csv + serializer.flush();
+}
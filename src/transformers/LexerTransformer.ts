@@ -1,4 +1,5 @@
 import {
+  BinaryOptions,
   CommonOptions,
   Field,
   FieldDelimiter,
@@ -6,7 +7,14 @@ import {
   Token,
 } from "../common/index.js";
 import { assertCommonOptions } from "../internal/assertCommonOptions.js";
-import { COMMA, CRLF, DOUBLE_QUATE, LF } from "../internal/constants.js";
+import {
+  BOM,
+  COMMA,
+  CR,
+  CRLF,
+  DOUBLE_QUATE,
+  LF,
+} from "../internal/constants.js";
 import { escapeRegExp } from "../internal/escapeRegExp.js";
 
 /**
@@ -40,20 +48,44 @@ export class LexerTransformer extends TransformStream<string, Token> {
   #demiliterLength: number;
   #quotation: string;
   #quotationLength: number;
+  #escape: string | undefined;
+  #escapeLength: number;
+  #comment: string | undefined;
+  #atRecordStart = true;
+  #atInputStart: boolean;
+  #atFieldStart = true;
+  #currentField: Token | null = null;
+  #relaxQuotes: boolean;
   #matcher: RegExp;
   #buffer = "";
+  /**
+   * Offset of the head of the buffer,
+   * counted in characters from the start of the input.
+   */
+  #offset = 0;
+  /**
+   * Line number of the head of the buffer (1-based).
+   */
+  #line = 1;
   public get demiliter(): string {
     return this.#demiliter;
   }
   public get quotation(): string {
     return this.#quotation;
   }
+  public get escape(): string | undefined {
+    return this.#escape;
+  }
 
   constructor({
     demiliter = COMMA,
     quotation = DOUBLE_QUATE,
-  }: CommonOptions = {}) {
-    assertCommonOptions({ demiliter, quotation });
+    escape,
+    comment,
+    relaxQuotes = false,
+    ignoreBOM = false,
+  }: CommonOptions & Pick<BinaryOptions, "ignoreBOM"> = {}) {
+    assertCommonOptions({ demiliter, quotation, escape, comment });
     super({
       transform: (
         chunk: string,
@@ -61,6 +93,15 @@ export class LexerTransformer extends TransformStream<string, Token> {
       ) => {
         if (chunk.length !== 0) {
           this.#buffer += chunk;
+          if (this.#atInputStart) {
+            this.#atInputStart = false;
+            // Strip the BOM so that it does not become part of the first field,
+            // unless it is asked to be kept by ignoreBOM.
+            if (this.#buffer.startsWith(BOM)) {
+              this.#buffer = this.#buffer.slice(BOM.length);
+              this.#offset += BOM.length;
+            }
+          }
           for (const token of this.#tokens({ flush: false })) {
             controller.enqueue(token);
           }
@@ -70,6 +111,13 @@ export class LexerTransformer extends TransformStream<string, Token> {
         for (const token of this.#tokens({ flush: true })) {
           controller.enqueue(token);
         }
+        if (this.#buffer.startsWith(this.#quotation)) {
+          throw new Error(
+            `Unexpected EOF while parsing quoted field: the quotation at line ${
+              this.#line
+            }, offset ${this.#offset} is never closed.`,
+          );
+        }
       },
     });
 
@@ -77,6 +125,12 @@ export class LexerTransformer extends TransformStream<string, Token> {
     this.#demiliterLength = demiliter.length;
     this.#quotation = quotation;
     this.#quotationLength = quotation.length;
+    // NOTE: escaping by the quotation itself is the default behavior.
+    this.#escape = escape === quotation ? undefined : escape;
+    this.#escapeLength = this.#escape?.length ?? 0;
+    this.#comment = comment;
+    this.#relaxQuotes = relaxQuotes;
+    this.#atInputStart = !ignoreBOM;
 
     const d = escapeRegExp(demiliter);
     const q = escapeRegExp(quotation);
@@ -85,61 +139,105 @@ export class LexerTransformer extends TransformStream<string, Token> {
     );
   }
 
+  /**
+   * Remove the consumed characters from the head of the buffer,
+   * keeping track of the offset and line number.
+   */
+  #consume(length: number): void {
+    for (let i = 0; i < length; i++) {
+      switch (this.#buffer[i]) {
+        case CR:
+          // NOTE: CRLF is counted as a single line break.
+          if (i + 1 < length && this.#buffer[i + 1] === LF) {
+            i++;
+          }
+          this.#line++;
+          break;
+        case LF:
+          this.#line++;
+          break;
+      }
+    }
+    this.#buffer = this.#buffer.slice(length);
+    this.#offset += length;
+  }
+
   *#tokens({ flush }: { flush: boolean }): Generator<Token> {
-    let currentField: Token | null = null;
     for (let token: Token | null; (token = this.#nextToken({ flush })); ) {
+      this.#atRecordStart = token.type === RecordDelimiter;
+      this.#atFieldStart = token.type !== Field;
       switch (token.type) {
         case Field:
-          if (currentField) {
-            currentField.value += token.value;
+          if (this.#currentField) {
+            this.#currentField.value += token.value;
           } else {
-            currentField = token;
+            this.#currentField = token;
           }
           break;
         case FieldDelimiter:
-          if (currentField) {
-            yield currentField;
-            currentField = null;
-          }
-          yield token;
-          break;
         case RecordDelimiter:
-          if (currentField) {
-            yield currentField;
-            currentField = null;
+          if (this.#currentField) {
+            yield this.#currentField;
+            this.#currentField = null;
           }
           yield token;
           break;
       }
     }
-    if (currentField) {
-      yield currentField;
+    // NOTE: The field may continue in the next chunk, so keep it until flushing.
+    if (flush && this.#currentField) {
+      yield this.#currentField;
+      this.#currentField = null;
     }
   }
 
   #nextToken({ flush = false } = {}): Token | null {
+    if (this.#atRecordStart && this.#skipComments(flush) === false) {
+      return null;
+    }
+
     if (this.#buffer.length === 0) {
       return null;
     }
 
     // Check for CRLF
     if (this.#buffer.startsWith(CRLF)) {
-      this.#buffer = this.#buffer.slice(2);
+      this.#consume(2);
       return { type: RecordDelimiter, value: CRLF };
     }
 
+    // Check for CR
+    if (this.#buffer.startsWith(CR)) {
+      // If not flushing, wait for the next chunk to tell CR from CRLF.
+      if (flush === false && this.#buffer.length === CR.length) {
+        return null;
+      }
+      this.#consume(1);
+      return { type: RecordDelimiter, value: CR };
+    }
+
     // Check for LF
     if (this.#buffer.startsWith(LF)) {
-      this.#buffer = this.#buffer.slice(1);
+      this.#consume(1);
       return { type: RecordDelimiter, value: LF };
     }
 
     // Check for Delimiter
     if (this.#buffer.startsWith(this.#demiliter)) {
-      this.#buffer = this.#buffer.slice(this.#demiliterLength);
+      this.#consume(this.#demiliterLength);
       return { type: FieldDelimiter, value: this.#demiliter };
     }
 
+    // Check for a stray quotation in the middle of an unquoted field
+    if (
+      this.#relaxQuotes &&
+      !this.#atFieldStart &&
+      this.#buffer.startsWith(this.#quotation)
+    ) {
+      this.#consume(this.#quotationLength);
+      return { type: Field, value: this.#quotation };
+    }
+
     // Check for Quoted String
     if (this.#buffer.startsWith(this.#quotation)) {
       // If not flushing and the buffer doesn't end with a quote, then return null.
@@ -157,7 +255,7 @@ export class LexerTransformer extends TransformStream<string, Token> {
       if (flush === false && match[0].length === this.#buffer.length) {
         return null;
       }
-      this.#buffer = this.#buffer.slice(match[0].length);
+      this.#consume(match[0].length);
       return { type: Field, value: match[0] };
     }
 
@@ -165,11 +263,72 @@ export class LexerTransformer extends TransformStream<string, Token> {
     return null;
   }
 
+  /**
+   * Skip the comment lines at the head of the buffer.
+   *
+   * @returns false if more data is needed to tell whether the line is a comment line.
+   */
+  #skipComments(flush: boolean): boolean {
+    const comment = this.#comment;
+    if (comment === undefined) {
+      return true;
+    }
+    while (this.#buffer.length !== 0) {
+      if (!this.#buffer.startsWith(comment)) {
+        // If not flushing, wait until the buffer is long enough to tell.
+        return flush || !comment.startsWith(this.#buffer);
+      }
+      const eol = /\r\n|\r|\n/.exec(this.#buffer);
+      if (eol === null) {
+        if (flush) {
+          this.#consume(this.#buffer.length);
+        }
+        return flush;
+      }
+      // If not flushing, wait for the next chunk to tell CR from CRLF.
+      if (
+        flush === false &&
+        eol[0] === CR &&
+        eol.index + CR.length === this.#buffer.length
+      ) {
+        return false;
+      }
+      this.#consume(eol.index + eol[0].length);
+    }
+    return true;
+  }
+
   private extractQuotedString(flush: boolean): Token | null {
     let end = this.#quotationLength; // Skip the opening quote
     let value = "";
 
     while (end < this.#buffer.length) {
+      // Escaped by the escape character
+      if (
+        this.#escape !== undefined &&
+        this.#buffer.startsWith(this.#escape, end)
+      ) {
+        const next = end + this.#escapeLength;
+        if (this.#buffer.startsWith(this.#quotation, next)) {
+          value += this.#quotation;
+          end = next + this.#quotationLength;
+          continue;
+        }
+        if (this.#buffer.startsWith(this.#escape, next)) {
+          value += this.#escape;
+          end = next + this.#escapeLength;
+          continue;
+        }
+        // If not flushing, wait for the character following the escape character.
+        if (
+          flush === false &&
+          next + Math.max(this.#quotationLength, this.#escapeLength) >
+            this.#buffer.length
+        ) {
+          return null;
+        }
+      }
+
       // Escaped quote
       if (
         this.#buffer.slice(end, end + this.#quotationLength) ===
@@ -203,13 +362,17 @@ export class LexerTransformer extends TransformStream<string, Token> {
           this.#buffer.slice(
             end + this.#quotationLength,
             end + this.#quotationLength + 1 /** LF.length */,
-          ) !== LF
+          ) !== LF &&
+          this.#buffer.slice(
+            end + this.#quotationLength,
+            end + this.#quotationLength + 1 /** CR.length */,
+          ) !== CR
         ) {
           return null;
         }
 
         // Otherwise, return the quoted string
-        this.#buffer = this.#buffer.slice(end + this.#quotationLength);
+        this.#consume(end + this.#quotationLength);
         return { type: Field, value };
       }
 
@@ -1,14 +1,41 @@
 import {
+  AssembledRecord,
+  ColumnCountPolicy,
+  CommonOptions,
+  ExtraDataPolicy,
   Field,
   FieldDelimiter,
+  HeaderRename,
   RecordAssemblerOptions,
   RecordDelimiter,
   Token,
+  TrimPolicy,
 } from "../common/index.js";
+import { DOUBLE_QUATE } from "../internal/constants.js";
+import { escapeField } from "../internal/escapeField.js";
+
+/**
+ * Fields of a record read from tokens, before it is assembled.
+ */
+interface RawRecord {
+  /**
+   * Record number, counted from 1.
+   */
+  number: number;
+  fields: string[];
+  delimiters: string[];
+  fieldCount: number;
+  /**
+   * Whether the record has no values, such as `,,`.
+   */
+  empty: boolean;
+}
 
 /**
  * A transform stream that converts a stream of tokens into a stream of rows.
  * @template Header The type of the header row.
+ * @template NullValues The type of the nullValues option.
+ * Values are `null` only if it is specified.
  * @param options The options for the parser.
  *
  * @category Low-level API
@@ -51,79 +78,379 @@ import {
  */
 export class RecordAssemblerTransformar<
   Header extends ReadonlyArray<string>,
-> extends TransformStream<Token, Record<Header[number], string | undefined>> {
+  NullValues extends ReadonlyArray<string> | undefined = undefined,
+> extends TransformStream<Token, AssembledRecord<Header, NullValues>> {
   #fieldIndex = 0;
   #row: string[] = [];
+  #delimiters: string[] = [];
   #header: Header | undefined;
+  #hasHeader: boolean;
+  #headerRows: number;
+  #headerJoin: string;
+  #headerRowsBuffer: string[][] = [];
   #darty = false;
+  #skipRows: number;
+  #skippedRows = 0;
+  #recordNumber = 0;
+  #skipFooterRows: number;
+  #footerRows: RawRecord[] = [];
+  #unitsRow: boolean;
+  #units: Record<Header[number], string | undefined> | undefined;
+  #fixHeader: boolean;
+  #extraData: ExtraDataPolicy;
+  #columnCount: ColumnCountPolicy;
+  #restColumn: string | undefined;
+  #quotation: string;
+  #escape: string | undefined;
+  #columns: ReadonlyArray<string | number> | undefined;
+  #selected: Set<number> | undefined;
+  #trim: TrimPolicy;
+  #nullValues: Set<string> | undefined;
+  #renamedHeaders: HeaderRename[] = [];
+
+  /**
+   * Header names renamed by the {@link RecordAssemblerOptions.fixHeader} option.
+   *
+   * @remarks
+   * Use this transformer directly to read the report,
+   * since the parse functions do not expose it.
+   */
+  public get renamedHeaders(): ReadonlyArray<HeaderRename> {
+    return this.#renamedHeaders;
+  }
 
-  constructor(options: RecordAssemblerOptions<Header> = {}) {
+  /**
+   * Units read from the units row.
+   *
+   * @remarks
+   * `undefined` until the units row is read,
+   * or if the {@link RecordAssemblerOptions.unitsRow} option is not specified.
+   */
+  public get units(): Record<Header[number], string | undefined> | undefined {
+    return this.#units;
+  }
+
+  constructor(
+    options: RecordAssemblerOptions<Header> &
+      Pick<CommonOptions, "quotation" | "escape"> & {
+        nullValues?: NullValues;
+      } = {},
+  ) {
     super({
       transform: (
         token: Token,
         controller: TransformStreamDefaultController<
-          Record<Header[number], string>
+          AssembledRecord<Header, NullValues>
         >,
       ) => {
         switch (token.type) {
           case Field:
             this.#darty = true;
-            this.#row[this.#fieldIndex] = token.value;
+            this.#row[this.#fieldIndex] = this.#trimField(token.value);
             break;
           case FieldDelimiter:
+            this.#delimiters[this.#fieldIndex] = token.value;
             this.#fieldIndex++;
             break;
           case RecordDelimiter:
-            if (this.#header === undefined) {
-              this.#setHeader(this.#row as unknown as Header);
-            } else {
-              if (this.#darty) {
-                const record = Object.fromEntries(
-                  this.#header
-                    .filter((v) => v)
-                    .map((header, index) => [header, this.#row.at(index)]),
-                ) as unknown as Record<Header[number], string>;
-                controller.enqueue(record);
-              }
-            }
+            this.#onRecord(controller);
             // Reset the row fields buffer.
             this.#fieldIndex = 0;
             this.#row = new Array(this.#header?.length);
+            this.#delimiters = [];
             this.#darty = false;
             break;
         }
       },
       flush: (
         controller: TransformStreamDefaultController<
-          Record<Header[number], string>
+          AssembledRecord<Header, NullValues>
         >,
       ) => {
-        if (this.#fieldIndex !== 0 && this.#header !== undefined) {
-          // console.log('B', this.#row)
-          if (this.#darty) {
-            const record = Object.fromEntries(
-              this.#header
-                .filter((v) => v)
-                .map((header, index) => [header, this.#row.at(index)]),
-            ) as unknown as Record<Header[number], string>;
-            controller.enqueue(record);
-          }
+        // Assemble the last record if the input has no trailing newline.
+        if (this.#darty || this.#fieldIndex !== 0) {
+          this.#onRecord(controller);
         }
       },
     });
 
+    this.#hasHeader = options.hasHeader ?? true;
+    this.#headerRows = options.headerRows ?? 1;
+    this.#headerJoin = options.headerJoin ?? ".";
+    if (!Number.isInteger(this.#headerRows) || this.#headerRows < 1) {
+      throw new Error("headerRows must be a positive integer.");
+    }
+    this.#skipRows = options.skipRows ?? 0;
+    this.#skipFooterRows = options.skipFooterRows ?? 0;
+    if (!Number.isInteger(this.#skipRows) || this.#skipRows < 0) {
+      throw new Error("skipRows must be a non-negative integer.");
+    }
+    if (!Number.isInteger(this.#skipFooterRows) || this.#skipFooterRows < 0) {
+      throw new Error("skipFooterRows must be a non-negative integer.");
+    }
+    this.#unitsRow = options.unitsRow ?? false;
+    this.#fixHeader = options.fixHeader ?? false;
+    this.#extraData = options.extraData ?? "drop";
+    this.#columnCount = options.columnCount ?? "loose";
+    this.#restColumn = options.restColumn;
+    this.#quotation = options.quotation ?? DOUBLE_QUATE;
+    this.#escape = options.escape;
+    this.#columns = options.columns;
+    this.#trim = options.trim ?? "none";
+    this.#nullValues =
+      options.nullValues !== undefined
+        ? new Set(options.nullValues)
+        : undefined;
     if (options.header !== undefined && Array.isArray(options.header)) {
       this.#setHeader(options.header);
     }
   }
 
+  #onRecord(
+    controller: TransformStreamDefaultController<
+      AssembledRecord<Header, NullValues>
+    >,
+  ) {
+    this.#recordNumber++;
+    if (this.#skippedRows < this.#skipRows) {
+      this.#skippedRows++;
+      return;
+    }
+    if (this.#header === undefined && !this.#hasHeader) {
+      if (!this.#darty) {
+        return;
+      }
+      this.#setHeader(
+        Array.from(
+          { length: this.#fieldIndex + 1 },
+          (_, index) => `column_${index}`,
+        ) as unknown as Header,
+      );
+    }
+    if (this.#header === undefined) {
+      // Skip empty lines before the header.
+      if (!this.#darty && this.#fieldIndex === 0) {
+        return;
+      }
+      // NOTE: Empty fields are not emitted as tokens,
+      // so pad the row to the number of fields, including trailing empty ones.
+      this.#headerRowsBuffer.push(
+        Array.from(
+          { length: this.#fieldIndex + 1 },
+          (_, index) => this.#row[index] ?? "",
+        ),
+      );
+      if (this.#headerRowsBuffer.length === this.#headerRows) {
+        this.#setHeader(this.#joinHeaderRows() as unknown as Header);
+      }
+    } else if (this.#unitsRow && this.#units === undefined) {
+      // Skip empty lines before the units row.
+      if (!this.#darty && this.#fieldIndex === 0) {
+        return;
+      }
+      this.#units = Object.fromEntries(
+        this.#header
+          .map((name, index) => [name, this.#row[index]])
+          .filter(([name]) => name),
+      ) as Record<Header[number], string | undefined>;
+    } else if (this.#darty || this.#fieldIndex !== 0) {
+      const record: RawRecord = {
+        number: this.#recordNumber,
+        fields: this.#row,
+        delimiters: this.#delimiters,
+        fieldCount: this.#fieldIndex + 1,
+        empty: !this.#darty,
+      };
+      if (this.#skipFooterRows === 0) {
+        this.#release(this.#header, record, controller);
+        return;
+      }
+      // Hold the last records back until they are known not to be footer rows.
+      // They are checked and assembled only when released,
+      // so footer rows of any width can be skipped.
+      this.#footerRows.push(record);
+      if (this.#footerRows.length > this.#skipFooterRows) {
+        // biome-ignore lint/style/noNonNullAssertion: the buffer is not empty.
+        this.#release(this.#header, this.#footerRows.shift()!, controller);
+      }
+    }
+  }
+
+  #release(
+    header: Header,
+    record: RawRecord,
+    controller: TransformStreamDefaultController<
+      AssembledRecord<Header, NullValues>
+    >,
+  ) {
+    if (this.#columnCount === "strict") {
+      this.#assertColumnCount(header, record);
+    }
+    // Records without values are not emitted.
+    if (record.empty) {
+      return;
+    }
+    controller.enqueue(this.#assemble(header, record));
+  }
+
+  #assertColumnCount(header: Header, { number, fieldCount }: RawRecord) {
+    if (
+      fieldCount < header.length ||
+      (fieldCount > header.length && this.#restColumn === undefined)
+    ) {
+      throw new Error(
+        `Record ${number} has ${fieldCount} fields, but the header has ${header.length} fields.`,
+      );
+    }
+  }
+
+  #trimField(value: string): string {
+    switch (this.#trim) {
+      case "left":
+        return value.trimStart();
+      case "right":
+        return value.trimEnd();
+      case "both":
+        return value.trim();
+      default:
+        return value;
+    }
+  }
+
+  #value(
+    { fields, fieldCount }: RawRecord,
+    index: number,
+  ): string | null | undefined {
+    const value = fields.at(index);
+    // NOTE: Empty fields are not emitted as tokens, so they are holes.
+    if (this.#nullValues?.has(value ?? "") && index < fieldCount) {
+      return null;
+    }
+    return value;
+  }
+
+  #assemble(
+    header: Header,
+    record: RawRecord,
+  ): AssembledRecord<Header, NullValues> {
+    const { number, fields, delimiters, fieldCount } = record;
+    const entries = header
+      .map((name, index) => [name, this.#value(record, index)])
+      .filter(
+        ([name], index) =>
+          name && (this.#selected === undefined || this.#selected.has(index)),
+      );
+    if (this.#restColumn !== undefined) {
+      let rest: string | undefined;
+      for (let index = header.length; index < fieldCount; index++) {
+        const demiliter = delimiters[index - 1];
+        // Escape the fields so that they can be told apart after joining.
+        const field = escapeField(fields[index] ?? "", {
+          demiliter,
+          quotation: this.#quotation,
+          escape: this.#escape,
+        });
+        rest = rest === undefined ? field : rest + demiliter + field;
+      }
+      entries.push([this.#restColumn, rest]);
+    } else if (this.#extraData === "error" && fieldCount > header.length) {
+      throw new Error(
+        `Record ${number} has ${fieldCount} fields, but the header has ${header.length} fields.`,
+      );
+    }
+    return Object.fromEntries(entries) as unknown as AssembledRecord<
+      Header,
+      NullValues
+    >;
+  }
+
+  #joinHeaderRows(): string[] {
+    const [first] = this.#headerRowsBuffer;
+    if (this.#headerRowsBuffer.length === 1) {
+      return first;
+    }
+    const width = Math.max(...this.#headerRowsBuffer.map((row) => row.length));
+    const lastRow = this.#headerRowsBuffer.length - 1;
+    // Carry over the values of merged cells, except for the last row.
+    const rows = this.#headerRowsBuffer.map((row, rowIndex) => {
+      let previous = "";
+      return Array.from({ length: width }, (_, index) => {
+        const value = row[index] ?? "";
+        if (rowIndex === lastRow) {
+          return value;
+        }
+        return (previous = value || previous);
+      });
+    });
+    return Array.from({ length: width }, (_, index) =>
+      rows
+        .map((row) => row[index])
+        .filter((value) => value)
+        .join(this.#headerJoin),
+    );
+  }
+
   #setHeader(header: Header) {
-    this.#header = header;
-    if (this.#header.length === 0) {
+    if (header.length === 0) {
       throw new Error("The header must not be empty.");
     }
-    if (new Set(this.#header).size !== this.#header.length) {
+    this.#header = this.#fixHeader
+      ? (this.#fix(header) as unknown as Header)
+      : header;
+    // NOTE: Columns with an empty name are dropped from records,
+    // so they may appear more than once.
+    const names = this.#header.filter((name) => name);
+    if (new Set(names).size !== names.length) {
       throw new Error("The header must not contain duplicate fields.");
     }
+    if (
+      this.#restColumn !== undefined &&
+      this.#header.includes(this.#restColumn)
+    ) {
+      throw new Error("The header must not contain the rest column.");
+    }
+    if (this.#columns !== undefined) {
+      const header = this.#header;
+      this.#selected = new Set(
+        this.#columns.map((column) => {
+          if (typeof column === "number") {
+            if (
+              !Number.isInteger(column) ||
+              column < 0 ||
+              column >= header.length
+            ) {
+              throw new Error(
+                `The column ${column} is out of range of the header.`,
+              );
+            }
+            return column;
+          }
+          const index = header.indexOf(column);
+          if (index === -1) {
+            throw new Error(
+              `The column "${column}" is not found in the header.`,
+            );
+          }
+          return index;
+        }),
+      );
+    }
+  }
+
+  #fix(header: ReadonlyArray<string | undefined>): string[] {
+    const seen = new Set<string>();
+    // NOTE: Array.from is used to visit the holes of a sparse header.
+    return Array.from(header, (name = "", index) => {
+      const base = name === "" ? `column_${index}` : name;
+      let fixed = base;
+      for (let n = 1; seen.has(fixed); n++) {
+        fixed = `${base}_${n}`;
+      }
+      seen.add(fixed);
+      if (fixed !== name) {
+        this.#renamedHeaders.push({ index, from: name, to: fixed });
+      }
+      return fixed;
+    });
   }
 }
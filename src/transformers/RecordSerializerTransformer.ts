@@ -0,0 +1,48 @@
+import { SerializableRecord, StringifyOptions } from "../common/index.js";
+import { RecordSerializer } from "../internal/RecordSerializer.js";
+
+/**
+ * A transform stream that converts a stream of records into CSV strings.
+ * @template Header The type of the header row.
+ * @param options The options for the serializer.
+ *
+ * @category Low-level API
+ *
+ * @remarks
+ * Each record is emitted as a chunk as soon as it is written,
+ * so huge datasets can be serialized without building the whole CSV string.
+ * Pipe it through {@link !TextEncoderStream} to get `Uint8Array` chunks.
+ *
+ * @example Serialize records to a CSV file
+ * ```ts
+ * new ReadableStream({
+ *   start(controller) {
+ *     controller.enqueue({ name: "Alice", age: "20" });
+ *     controller.enqueue({ name: "Bob", age: "25" });
+ *     controller.close();
+ *   }
+ * })
+ * .pipeThrough(new RecordSerializerTransformer())
+ * .pipeTo(new WritableStream({ write(chunk) { console.log(chunk); }}));
+ * // "name,age\r\nAlice,20\r\n"
+ * // "Bob,25\r\n"
+ * ```
+ */
+export class RecordSerializerTransformer<
+  Header extends ReadonlyArray<string>,
+> extends TransformStream<SerializableRecord<Header>, string> {
+  constructor(options: StringifyOptions<Header> = {}) {
+    const serializer = new RecordSerializer(options);
+    super({
+      transform: (record, controller) => {
+        controller.enqueue(serializer.serialize(record));
+      },
+      flush: (controller) => {
+        const csv = serializer.flush();
+        if (csv !== "") {
+          controller.enqueue(csv);
+        }
+      },
+    });
+  }
+}
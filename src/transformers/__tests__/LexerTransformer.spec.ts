@@ -2,7 +2,7 @@ import { fc } from "@fast-check/vitest";
 import { describe as describe_, expect, it as it_ } from "vitest";
 import { FC, autoChunk, transform } from "../../__tests__/helper.js";
 import { Field, FieldDelimiter, RecordDelimiter } from "../../common/index.js";
-import { COMMA, DOUBLE_QUATE } from "../../internal/constants.js";
+import { BOM, COMMA, CR, DOUBLE_QUATE, LF } from "../../internal/constants.js";
 import { escapeField } from "../../internal/escapeField.js";
 import { LexerTransformer } from "../LexerTransformer.js";
 
@@ -76,6 +76,15 @@ describe("LexerTransformer", () => {
       ),
     ));
 
+  it("should be throw error if demiliter or quotation starts with BOM", () => {
+    expect(() => new LexerTransformer({ quotation: `${BOM}"` })).toThrowError(
+      "quotation must not start with BOM",
+    );
+    expect(() => new LexerTransformer({ demiliter: BOM })).toThrowError(
+      "demiliter must not start with BOM",
+    );
+  });
+
   it("should separate fields by commas by default", () =>
     fc.assert(
       fc.asyncProperty(
@@ -277,4 +286,232 @@ describe("LexerTransformer", () => {
         },
       ),
     ));
+
+  it("should throw an error with the position of an unclosed quotation", () =>
+    expect(
+      transform(new LexerTransformer(), ['a,b\nc,"d', "\ne,f\n"]),
+    ).rejects.toThrowError(
+      "Unexpected EOF while parsing quoted field: the quotation at line 2, offset 6 is never closed.",
+    ));
+
+  it("should count CRLF as a single line break and the BOM in the offset", () =>
+    expect(
+      transform(new LexerTransformer(), [`${BOM}a\r\nb\rc\n"d`]),
+    ).rejects.toThrowError(
+      "Unexpected EOF while parsing quoted field: the quotation at line 4, offset 8 is never closed.",
+    ));
+
+  it("should be treated as a record delimiter if CR only", () =>
+    fc.assert(
+      fc.asyncProperty(
+        fc.gen().map((g) => {
+          const data = g(FC.csvData, {
+            rowsConstraints: { minLength: 1 },
+            columnsConstraints: { minLength: 1 },
+            fieldConstraints: { minLength: 1 },
+          });
+          const chunks = autoChunk(
+            g,
+            data
+              .map((row) => row.map((value) => escapeField(value)).join(","))
+              .join(CR),
+          );
+          return { data, chunks };
+        }),
+        async ({ data, chunks }) => {
+          const expected = data.flatMap((row, i) => [
+            ...row.flatMap((value, j) => [
+              { type: Field, value },
+              ...(j === row.length - 1
+                ? []
+                : [{ type: FieldDelimiter, value: "," }]),
+            ]),
+            ...(i === data.length - 1
+              ? []
+              : [{ type: RecordDelimiter, value: CR }]),
+          ]);
+          const actual = await transform(new LexerTransformer(), chunks);
+          expect(actual).toStrictEqual(expected);
+        },
+      ),
+      {
+        examples: [[{ data: [["a"], ["b"]], chunks: ["a\r", "b"] }]],
+      },
+    ));
+
+  it("should lex quoted fields with line breaks at any chunk boundary", async () => {
+    const csv = '"a\r\nb","c\rd"\r\n"e\nf"\r';
+    const expected = [
+      { type: Field, value: "a\r\nb" },
+      { type: FieldDelimiter, value: COMMA },
+      { type: Field, value: "c\rd" },
+      { type: RecordDelimiter, value: "\r\n" },
+      { type: Field, value: "e\nf" },
+      { type: RecordDelimiter, value: CR },
+    ];
+    for (let i = 0; i <= csv.length; i++) {
+      for (let j = i; j <= csv.length; j++) {
+        const chunks = [csv.slice(0, i), csv.slice(i, j), csv.slice(j)];
+        const actual = await transform(new LexerTransformer(), chunks);
+        expect(actual).toStrictEqual(expected);
+      }
+    }
+  });
+
+  describe("escape option", () => {
+    it("should be throw error if escape is a empty character", () => {
+      expect(() => new LexerTransformer({ escape: "" })).toThrowError(
+        "escape must not be empty",
+      );
+    });
+
+    it("should unescape quotations and escape characters in quoted fields", async () => {
+      const lexer = new LexerTransformer({ escape: "\\" });
+      expect(lexer.escape).toBe("\\");
+      const actual = await transform(lexer, ['"a\\"b","c\\\\d","e""f"']);
+      expect(actual).toStrictEqual([
+        { type: Field, value: 'a"b' },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "c\\d" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: 'e"f' },
+      ]);
+    });
+
+    it("should unescape across chunk boundaries", () =>
+      fc.assert(
+        fc.asyncProperty(
+          fc.gen().map((g) => {
+            const row = g(FC.row);
+            const chunks = autoChunk(
+              g,
+              row
+                .map(
+                  (v) =>
+                    `"${v.replaceAll("\\", "\\\\").replaceAll('"', '\\"')}"`,
+                )
+                .join(","),
+            );
+            return { row, chunks };
+          }),
+          async ({ row, chunks }) => {
+            const actual = await transform(
+              new LexerTransformer({ escape: "\\" }),
+              chunks,
+            );
+            expect(actual).toStrictEqual([
+              ...row.flatMap((value, index) => [
+                { type: Field, value },
+                ...(index === row.length - 1
+                  ? []
+                  : [{ type: FieldDelimiter, value: "," }]),
+              ]),
+            ]);
+          },
+        ),
+      ));
+  });
+
+  it("should strip the BOM at the start of the input", async () => {
+    const actual = await transform(new LexerTransformer(), [
+      "",
+      `${BOM}a`,
+      `,${BOM}b`,
+    ]);
+    expect(actual).toStrictEqual([
+      { type: Field, value: "a" },
+      { type: FieldDelimiter, value: COMMA },
+      { type: Field, value: `${BOM}b` },
+    ]);
+  });
+
+  it("should keep the BOM if ignoreBOM is true", async () => {
+    const actual = await transform(new LexerTransformer({ ignoreBOM: true }), [
+      `${BOM}a`,
+    ]);
+    expect(actual).toStrictEqual([{ type: Field, value: `${BOM}a` }]);
+  });
+
+  describe("comment option", () => {
+    it("should be throw error if comment is a empty character", () => {
+      expect(() => new LexerTransformer({ comment: "" })).toThrowError(
+        "comment must not be empty",
+      );
+    });
+
+    it("should be throw error if comment overlaps the demiliter", () => {
+      expect(() => new LexerTransformer({ comment: "," })).toThrowError(
+        "demiliter and comment must not include each other as a substring",
+      );
+      expect(
+        () => new LexerTransformer({ demiliter: "#", comment: "##" }),
+      ).toThrowError(
+        "demiliter and comment must not include each other as a substring",
+      );
+    });
+
+    it("should be throw error if comment overlaps the quotation", () => {
+      expect(() => new LexerTransformer({ comment: '"' })).toThrowError(
+        "quotation and comment must not include each other as a substring",
+      );
+      expect(
+        () => new LexerTransformer({ quotation: "$", comment: "#$" }),
+      ).toThrowError(
+        "quotation and comment must not include each other as a substring",
+      );
+    });
+
+    it("should skip comment lines", () =>
+      fc.assert(
+        fc.asyncProperty(
+          fc.gen().map((g) => {
+            const EOL = g(FC.eol);
+            const comment = g(FC.field, { excludes: [CR, LF] });
+            const chunks = autoChunk(
+              g,
+              `##${comment}${EOL}a,b${EOL}##${comment}${EOL}"#",##${EOL}##`,
+            );
+            return { EOL, chunks };
+          }),
+          async ({ EOL, chunks }) => {
+            const actual = await transform(
+              new LexerTransformer({ comment: "##" }),
+              chunks,
+            );
+            expect(actual).toStrictEqual([
+              { type: Field, value: "a" },
+              { type: FieldDelimiter, value: "," },
+              { type: Field, value: "b" },
+              { type: RecordDelimiter, value: EOL },
+              { type: Field, value: "#" },
+              { type: FieldDelimiter, value: "," },
+              { type: Field, value: "##" },
+              { type: RecordDelimiter, value: EOL },
+            ]);
+          },
+        ),
+      ));
+  });
+
+  describe("relaxQuotes option", () => {
+    it("should keep stray quotations in unquoted fields", async () => {
+      const csv = 'he said "hi", there,"a,b"\nx"y';
+      const expected = [
+        { type: Field, value: 'he said "hi"' },
+        { type: FieldDelimiter, value: COMMA },
+        { type: Field, value: " there" },
+        { type: FieldDelimiter, value: COMMA },
+        { type: Field, value: "a,b" },
+        { type: RecordDelimiter, value: LF },
+        { type: Field, value: 'x"y' },
+      ];
+      for (let i = 0; i <= csv.length; i++) {
+        const actual = await transform(
+          new LexerTransformer({ relaxQuotes: true }),
+          [csv.slice(0, i), csv.slice(i)],
+        );
+        expect(actual).toStrictEqual(expected);
+      }
+    });
+  });
 });
@@ -110,4 +110,589 @@ describe("ParserTransformer", () => {
         },
       ),
     ));
+
+  describe("fixHeader option", () => {
+    it("should rename empty and duplicated header fields", async () => {
+      const parser = new RecordAssemblerTransformar({ fixHeader: true });
+      const actual = await transform(parser, [
+        { type: Field, value: "a" },
+        { type: FieldDelimiter, value: "," },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "a" },
+        { type: RecordDelimiter, value: "\n" },
+        { type: Field, value: "1" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "2" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "3" },
+        { type: RecordDelimiter, value: "\n" },
+      ]);
+      expect(actual).toStrictEqual([{ a: "1", column_1: "2", a_1: "3" }]);
+      expect(parser.renamedHeaders).toStrictEqual([
+        { index: 1, from: "", to: "column_1" },
+        { index: 2, from: "a", to: "a_1" },
+      ]);
+    });
+
+    it("should rename a trailing empty header field", async () => {
+      const parser = new RecordAssemblerTransformar({ fixHeader: true });
+      const actual = await transform(parser, [
+        { type: Field, value: "a" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "b" },
+        { type: FieldDelimiter, value: "," },
+        { type: RecordDelimiter, value: "\n" },
+        { type: Field, value: "1" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "2" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "3" },
+        { type: RecordDelimiter, value: "\n" },
+      ]);
+      expect(actual).toStrictEqual([{ a: "1", b: "2", column_2: "3" }]);
+      expect(parser.renamedHeaders).toStrictEqual([
+        { index: 2, from: "", to: "column_2" },
+      ]);
+    });
+
+    it("should fix the header given by option", () => {
+      const parser = new RecordAssemblerTransformar({
+        header: ["a", "a", "a_1"],
+        fixHeader: true,
+      });
+      expect(parser.renamedHeaders).toStrictEqual([
+        { index: 1, from: "a", to: "a_1" },
+        { index: 2, from: "a_1", to: "a_1_1" },
+      ]);
+    });
+
+    it("should not rename a valid header", () => {
+      const parser = new RecordAssemblerTransformar({
+        header: ["a", "b"],
+        fixHeader: true,
+      });
+      expect(parser.renamedHeaders).toStrictEqual([]);
+    });
+  });
+
+  describe("extraData option", () => {
+    const tokens = [
+      { type: Field, value: "1" },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "2" },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "3" },
+      { type: RecordDelimiter, value: "\n" },
+    ];
+
+    it("should drop extra fields by default", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({ header: ["a", "b"] }),
+        tokens,
+      );
+      expect(actual).toStrictEqual([{ a: "1", b: "2" }]);
+    });
+
+    it("should throw an error if extraData is 'error'", () =>
+      expect(
+        transform(
+          new RecordAssemblerTransformar({
+            header: ["a", "b"],
+            extraData: "error",
+          }),
+          tokens,
+        ),
+      ).rejects.toThrowError(
+        "Record 1 has 3 fields, but the header has 2 fields.",
+      ));
+
+    it("should count a trailing empty header field", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({ extraData: "error" }),
+        [
+          { type: Field, value: "a" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "b" },
+          { type: FieldDelimiter, value: "," },
+          { type: RecordDelimiter, value: "\n" },
+          { type: Field, value: "1" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "2" },
+          { type: FieldDelimiter, value: "," },
+          { type: RecordDelimiter, value: "\n" },
+        ],
+      );
+      expect(actual).toStrictEqual([{ a: "1", b: "2" }]);
+    });
+  });
+
+  it("should keep values aligned with their header when a header name is empty", async () => {
+    const actual = await transform(new RecordAssemblerTransformar(), [
+      { type: Field, value: "a" },
+      { type: FieldDelimiter, value: "," },
+      { type: FieldDelimiter, value: "," },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "d" },
+      { type: RecordDelimiter, value: "\n" },
+      { type: Field, value: "1" },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "2" },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "3" },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "4" },
+      { type: RecordDelimiter, value: "\n" },
+    ]);
+    expect(actual).toStrictEqual([{ a: "1", d: "4" }]);
+  });
+
+  describe("restColumn option", () => {
+    it("should collect extra fields into the rest column", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({
+          header: ["a", "b"],
+          restColumn: "_extra",
+        }),
+        [
+          { type: Field, value: "1" },
+          { type: FieldDelimiter, value: ";" },
+          { type: Field, value: "2" },
+          { type: FieldDelimiter, value: ";" },
+          { type: Field, value: "3" },
+          { type: FieldDelimiter, value: ";" },
+          { type: FieldDelimiter, value: ";" },
+          { type: Field, value: "5" },
+          { type: RecordDelimiter, value: "\n" },
+          { type: Field, value: "1" },
+          { type: RecordDelimiter, value: "\n" },
+        ],
+      );
+      expect(actual).toStrictEqual([
+        { a: "1", b: "2", _extra: "3;;5" },
+        { a: "1", b: undefined, _extra: undefined },
+      ]);
+    });
+
+    it("should escape the extra fields before joining them", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({
+          header: ["a", "b"],
+          restColumn: "_extra",
+        }),
+        [
+          { type: Field, value: "1" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "2" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "x,y" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: 'z"' },
+          { type: RecordDelimiter, value: "\n" },
+        ],
+      );
+      expect(actual).toStrictEqual([
+        { a: "1", b: "2", _extra: '"x,y","z"""' },
+      ]);
+    });
+
+    it("should escape the extra fields with the escape character", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({
+          header: ["a"],
+          restColumn: "_extra",
+          escape: "\\",
+        }),
+        [
+          { type: Field, value: "1" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: 'a"b\\' },
+          { type: RecordDelimiter, value: "\n" },
+        ],
+      );
+      expect(actual).toStrictEqual([{ a: "1", _extra: '"a\\"b\\\\"' }]);
+    });
+
+    it("should throw error if the header contains the rest column", () => {
+      expect(
+        () =>
+          new RecordAssemblerTransformar({
+            header: ["a", "_extra"],
+            restColumn: "_extra",
+          }),
+      ).toThrowError("The header must not contain the rest column.");
+    });
+  });
+
+  describe("headerRows option", () => {
+    it("should throw error if headerRows is not a positive integer", () => {
+      expect(
+        () => new RecordAssemblerTransformar({ headerRows: 0 }),
+      ).toThrowError("headerRows must be a positive integer.");
+    });
+
+    it("should combine multiple header rows", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({ headerRows: 2 }),
+        [
+          // ",position,\n"
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "position" },
+          { type: FieldDelimiter, value: "," },
+          { type: RecordDelimiter, value: "\n" },
+          // "id,x,y\n"
+          { type: Field, value: "id" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "x" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "y" },
+          { type: RecordDelimiter, value: "\n" },
+          // "1,2,3\n"
+          { type: Field, value: "1" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "2" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "3" },
+          { type: RecordDelimiter, value: "\n" },
+        ],
+      );
+      expect(actual).toStrictEqual([
+        { id: "1", "position.x": "2", "position.y": "3" },
+      ]);
+    });
+
+    it("should join header rows with headerJoin", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({ headerRows: 2, headerJoin: "_" }),
+        [
+          { type: Field, value: "a" },
+          { type: RecordDelimiter, value: "\n" },
+          { type: Field, value: "b" },
+          { type: RecordDelimiter, value: "\n" },
+          { type: Field, value: "1" },
+          { type: RecordDelimiter, value: "\n" },
+        ],
+      );
+      expect(actual).toStrictEqual([{ a_b: "1" }]);
+    });
+  });
+
+  describe("unitsRow option", () => {
+    it("should capture the record following the header as units", async () => {
+      const parser = new RecordAssemblerTransformar({ unitsRow: true });
+      expect(parser.units).toBeUndefined();
+      const actual = await transform(parser, [
+        { type: Field, value: "time" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "voltage" },
+        { type: RecordDelimiter, value: "\n" },
+        { type: Field, value: "s" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "V" },
+        { type: RecordDelimiter, value: "\n" },
+        { type: Field, value: "0.1" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "3.3" },
+        { type: RecordDelimiter, value: "\n" },
+      ]);
+      expect(actual).toStrictEqual([{ time: "0.1", voltage: "3.3" }]);
+      expect(parser.units).toStrictEqual({ time: "s", voltage: "V" });
+    });
+
+    it("should skip empty lines and not apply record options to the units row", async () => {
+      const parser = new RecordAssemblerTransformar({
+        unitsRow: true,
+        nullValues: [""],
+        restColumn: "_extra",
+      });
+      const actual = await transform(parser, [
+        { type: Field, value: "time" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "voltage" },
+        { type: RecordDelimiter, value: "\n" },
+        { type: RecordDelimiter, value: "\n" },
+        { type: Field, value: "s" },
+        { type: FieldDelimiter, value: "," },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "x" },
+        { type: RecordDelimiter, value: "\n" },
+        { type: Field, value: "0.1" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "3.3" },
+        { type: RecordDelimiter, value: "\n" },
+      ]);
+      expect(actual).toStrictEqual([
+        { time: "0.1", voltage: "3.3", _extra: undefined },
+      ]);
+      expect(parser.units).toStrictEqual({ time: "s", voltage: undefined });
+    });
+
+    it("should capture a units row without values", async () => {
+      const parser = new RecordAssemblerTransformar({ unitsRow: true });
+      const actual = await transform(parser, [
+        { type: Field, value: "id" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "name" },
+        { type: RecordDelimiter, value: "\n" },
+        { type: FieldDelimiter, value: "," },
+        { type: RecordDelimiter, value: "\n" },
+        { type: Field, value: "1" },
+        { type: FieldDelimiter, value: "," },
+        { type: Field, value: "Alice" },
+        { type: RecordDelimiter, value: "\n" },
+      ]);
+      expect(actual).toStrictEqual([{ id: "1", name: "Alice" }]);
+      expect(parser.units).toStrictEqual({ id: undefined, name: undefined });
+    });
+  });
+
+  describe("skipRows and skipFooterRows options", () => {
+    it("should not check the width of footer rows", async () => {
+      const toTokens = (...rows: string[][]) =>
+        rows.flatMap((row) => [
+          ...row.flatMap((value, i) => [
+            ...(i === 0 ? [] : [{ type: FieldDelimiter, value: "," }]),
+            { type: Field, value },
+          ]),
+          { type: RecordDelimiter, value: "\n" },
+        ]);
+      for (const footer of [["Total", "100"], ["Total", "1", "2", "3"]]) {
+        const actual = await transform(
+          new RecordAssemblerTransformar({
+            skipFooterRows: 1,
+            columnCount: "strict",
+            extraData: "error",
+          }),
+          toTokens(["a", "b", "c"], ["1", "2", "3"], footer),
+        );
+        expect(actual).toStrictEqual([{ a: "1", b: "2", c: "3" }]);
+      }
+    });
+
+    it("should throw error if skipRows is negative", () => {
+      expect(
+        () => new RecordAssemblerTransformar({ skipRows: -1 }),
+      ).toThrowError("skipRows must be a non-negative integer.");
+    });
+
+    it("should skip preamble and footer records", () =>
+      fc.assert(
+        fc.asyncProperty(
+          fc.gen().map((g) => {
+            const EOL = g(FC.eol);
+            const header = g(FC.header);
+            const preamble = g(FC.csvData);
+            const rows = g(FC.csvData, {
+              columnsConstraints: {
+                minLength: header.length,
+                maxLength: header.length,
+              },
+              fieldConstraints: { minLength: 1 },
+            });
+            const footer = g(FC.csvData, {
+              columnsConstraints: { minLength: 1 },
+              fieldConstraints: { minLength: 1 },
+            });
+            const toTokens = (row: string[]) => [
+              ...row.flatMap((value, i) => [
+                { type: Field, value },
+                ...(i === row.length - 1
+                  ? []
+                  : [{ type: FieldDelimiter, value: "," }]),
+              ]),
+              { type: RecordDelimiter, value: EOL },
+            ];
+            const tokens = [
+              ...preamble.flatMap(toTokens),
+              ...toTokens(header),
+              ...rows.flatMap(toTokens),
+              ...footer.flatMap(toTokens),
+            ];
+            const expected = rows.map((row) =>
+              Object.fromEntries(row.map((field, i) => [header[i], field])),
+            );
+            return { preamble, footer, tokens, expected };
+          }),
+          async ({ preamble, footer, tokens, expected }) => {
+            const actual = await transform(
+              new RecordAssemblerTransformar({
+                skipRows: preamble.length,
+                skipFooterRows: footer.length,
+              }),
+              tokens,
+            );
+            expect(actual).toEqual(expected);
+          },
+        ),
+      ));
+  });
+
+  describe("hasHeader option", () => {
+    it("should treat the first record as data and generate header names", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({ hasHeader: false }),
+        [
+          { type: Field, value: "Alice" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "20" },
+          { type: RecordDelimiter, value: "\n" },
+          { type: Field, value: "Bob" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "25" },
+        ],
+      );
+      expect(actual).toStrictEqual([
+        { column_0: "Alice", column_1: "20" },
+        { column_0: "Bob", column_1: "25" },
+      ]);
+    });
+  });
+
+  describe("columns option", () => {
+    const tokens = [
+      { type: Field, value: "name" },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "age" },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "city" },
+      { type: RecordDelimiter, value: "\n" },
+      { type: Field, value: "Alice" },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "20" },
+      { type: FieldDelimiter, value: "," },
+      { type: Field, value: "Tokyo" },
+      { type: RecordDelimiter, value: "\n" },
+    ];
+
+    it("should include only the specified columns", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({ columns: ["city", 0] }),
+        tokens,
+      );
+      expect(actual).toStrictEqual([{ name: "Alice", city: "Tokyo" }]);
+    });
+
+    it("should throw error if a column is not found in the header", async () => {
+      await expect(
+        transform(new RecordAssemblerTransformar({ columns: ["zip"] }), tokens),
+      ).rejects.toThrowError('The column "zip" is not found in the header.');
+    });
+
+    it("should throw error if a column index is out of range", async () => {
+      for (const column of [-1, 1.5, 3]) {
+        await expect(
+          transform(
+            new RecordAssemblerTransformar({ columns: [column] }),
+            tokens,
+          ),
+        ).rejects.toThrowError(
+          `The column ${column} is out of range of the header.`,
+        );
+      }
+    });
+  });
+
+  describe("trim option", () => {
+    it("should trim whitespace from the specified side of fields", async () => {
+      const tokens = [
+        { type: Field, value: " name " },
+        { type: RecordDelimiter, value: "\n" },
+        { type: Field, value: "  Alice\t" },
+        { type: RecordDelimiter, value: "\n" },
+      ];
+      for (const [trim, expected] of [
+        ["none", [{ " name ": "  Alice\t" }]],
+        ["left", [{ "name ": "Alice\t" }]],
+        ["right", [{ " name": "  Alice" }]],
+        ["both", [{ name: "Alice" }]],
+      ] as const) {
+        const actual = await transform(
+          new RecordAssemblerTransformar({ trim }),
+          tokens,
+        );
+        expect(actual).toStrictEqual(expected);
+      }
+    });
+  });
+
+  describe("nullValues option", () => {
+    it("should emit null for fields that match one of the values", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({
+          header: ["a", "b", "c", "d"],
+          nullValues: ["", "NA"],
+        }),
+        [
+          { type: Field, value: "NA" },
+          { type: FieldDelimiter, value: "," },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "x" },
+          { type: RecordDelimiter, value: "\n" },
+        ],
+      );
+      expect(actual).toStrictEqual([
+        { a: null, b: null, c: "x", d: undefined },
+      ]);
+    });
+  });
+
+  describe("columnCount option", () => {
+    it("should throw error if a record has a different number of fields", async () => {
+      await expect(
+        transform(new RecordAssemblerTransformar({ columnCount: "strict" }), [
+          { type: Field, value: "a" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "b" },
+          { type: RecordDelimiter, value: "\n" },
+          { type: Field, value: "1" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "2" },
+          { type: RecordDelimiter, value: "\n" },
+          { type: Field, value: "3" },
+          { type: RecordDelimiter, value: "\n" },
+        ]),
+      ).rejects.toThrowError(
+        "Record 3 has 1 fields, but the header has 2 fields.",
+      );
+    });
+
+    it("should count a trailing empty header field", async () => {
+      const actual = await transform(
+        new RecordAssemblerTransformar({ columnCount: "strict" }),
+        [
+          { type: Field, value: "a" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "b" },
+          { type: FieldDelimiter, value: "," },
+          { type: RecordDelimiter, value: "\n" },
+          { type: Field, value: "1" },
+          { type: FieldDelimiter, value: "," },
+          { type: Field, value: "2" },
+          { type: FieldDelimiter, value: "," },
+          { type: RecordDelimiter, value: "\n" },
+        ],
+      );
+      expect(actual).toStrictEqual([{ a: "1", b: "2" }]);
+    });
+
+    it("should throw error if a record without values has a different number of fields", async () => {
+      await expect(
+        transform(
+          new RecordAssemblerTransformar({
+            header: ["a", "b"],
+            columnCount: "strict",
+          }),
+          [
+            { type: FieldDelimiter, value: "," },
+            { type: RecordDelimiter, value: "\n" },
+            { type: FieldDelimiter, value: "," },
+            { type: FieldDelimiter, value: "," },
+            { type: FieldDelimiter, value: "," },
+            { type: RecordDelimiter, value: "\n" },
+          ],
+        ),
+      ).rejects.toThrowError(
+        "Record 2 has 4 fields, but the header has 2 fields.",
+      );
+    });
+  });
 });
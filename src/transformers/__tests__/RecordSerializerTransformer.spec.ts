@@ -0,0 +1,48 @@
+import { fc } from "@fast-check/vitest";
+import { describe, expect, it } from "vitest";
+import { FC, transform } from "../../__tests__/helper.js";
+import { stringify } from "../../stringify.js";
+import { RecordSerializerTransformer } from "../RecordSerializerTransformer.js";
+
+describe("RecordSerializerTransformer", () => {
+  it("should emit a chunk for each record", async () => {
+    const actual = await transform(new RecordSerializerTransformer(), [
+      { name: "Alice", age: "20" },
+      { name: "Bob", age: "25" },
+    ]);
+    expect(actual).toStrictEqual(["name,age\r\nAlice,20\r\n", "Bob,25\r\n"]);
+  });
+
+  it("should emit the header if there are no records", async () => {
+    const actual = await transform(
+      new RecordSerializerTransformer({ header: ["name", "age"] }),
+      [],
+    );
+    expect(actual).toStrictEqual(["name,age\r\n"]);
+  });
+
+  it("should emit the same CSV as stringify", () =>
+    fc.assert(
+      fc.asyncProperty(
+        fc.gen().map((g) => {
+          const header = g(FC.header);
+          const records = g(FC.csvData, {
+            columnsConstraints: {
+              minLength: header.length,
+              maxLength: header.length,
+            },
+          }).map((row) =>
+            Object.fromEntries(row.map((v, i) => [header[i], v])),
+          );
+          return { header, records };
+        }),
+        async ({ header, records }) => {
+          const actual = await transform(
+            new RecordSerializerTransformer({ header }),
+            records,
+          );
+          expect(actual.join("")).toBe(stringify(records, { header }));
+        },
+      ),
+    ));
+});
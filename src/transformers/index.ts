@@ -1,2 +1,3 @@
 export * from "./LexerTransformer.js";
 export * from "./RecordAssemblerTransformar.js";
+export * from "./RecordSerializerTransformer.js";
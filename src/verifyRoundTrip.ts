@@ -0,0 +1,59 @@
+import { CommonOptions, RoundTripDifference } from "./common/types.js";
+import { parseString } from "./parseString.js";
+import { stringify } from "./stringify.js";
+
+/**
+ * Check that CSV is read back the same after it is parsed and serialized.
+ *
+ * @category Middle-level API
+ * @param input CSV string to check.
+ * @param dialect The dialect used to parse, serialize, and parse again.
+ * @returns The differences between the records parsed from the input
+ * and those parsed from the serialized CSV. Empty if they are the same.
+ *
+ * @remarks
+ * Use this function to make sure that the data is not changed
+ * before using {@link stringify} for migrations.
+ *
+ * @example Checking a CSV string
+ *
+ * ```ts
+ * import { verifyRoundTrip } from 'web-csv-toolbox';
+ *
+ * const differences = await verifyRoundTrip('name,age\nAlice,42\nBob,""');
+ * console.log(differences);
+ * // Prints:
+ * // [ { index: 1, column: 'age', expected: '', actual: undefined } ]
+ * ```
+ */
+export async function verifyRoundTrip(
+  input: string,
+  dialect: Pick<CommonOptions, "demiliter" | "quotation" | "escape"> = {},
+): Promise<RoundTripDifference[]> {
+  const expected = await parseString.toArray(input, dialect);
+  if (expected.length === 0) {
+    return [];
+  }
+  const header = Object.keys(expected[0]);
+  const actual = await parseString.toArray(
+    stringify(expected, { ...dialect, header }),
+    dialect,
+  );
+  const differences: RoundTripDifference[] = [];
+  const length = Math.max(expected.length, actual.length);
+  for (let index = 0; index < length; index++) {
+    const e: Record<string, string | undefined> = expected[index] ?? {};
+    const a: Record<string, string | undefined> = actual[index] ?? {};
+    for (const column of new Set([...Object.keys(e), ...Object.keys(a)])) {
+      if (e[column] !== a[column]) {
+        differences.push({
+          index,
+          column,
+          expected: e[column],
+          actual: a[column],
+        });
+      }
+    }
+  }
+  return differences;
+}
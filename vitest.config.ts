@@ -4,6 +4,9 @@ export default defineConfig({
   test: {
     setupFiles: ["config/vitest.setup.ts"],
     exclude: ["node_modules", "lib", "**/*.deno.*.ts"],
+    typecheck: {
+      enabled: true,
+    },
     browser: {
       name: "chrome",
     },